@@ -0,0 +1,418 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use alloy_primitives::B256;
+use anyhow::{anyhow, bail};
+use ream_bls::PublicKey;
+use serde::{Deserialize, Serialize};
+
+/// Interchange format version understood by this implementation, per EIP-3076.
+const INTERCHANGE_FORMAT_VERSION: &str = "5";
+
+/// The minimal-state slashing history tracked for a single validator.
+///
+/// Following the EIP-3076 minimal-state rules we only keep the highest signed
+/// block slot and the extremal attestation source/target epochs, which is
+/// enough to detect double proposals, double votes, and surround votes without
+/// storing the full signing history.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct ValidatorHistory {
+    highest_signed_block_slot: Option<u64>,
+    max_signed_source_epoch: Option<u64>,
+    max_signed_target_epoch: Option<u64>,
+}
+
+/// Persistent EIP-3076 slashing-protection store.
+///
+/// Every block/attestation is checked against the store and, if safe, recorded
+/// in the same atomic step before it is signed. Updates are flushed to disk *before* the signed
+/// message is broadcast, so a crash can never leave us having gossiped a
+/// signature we have not durably recorded.
+pub struct SlashingProtection {
+    path: PathBuf,
+    histories: Mutex<HashMap<PublicKey, ValidatorHistory>>,
+}
+
+impl SlashingProtection {
+    /// Open the store at `path`, loading any previously persisted history.
+    pub fn new(path: PathBuf) -> anyhow::Result<Self> {
+        let histories = if path.exists() {
+            let raw = fs::read(&path)
+                .map_err(|err| anyhow!("Failed to read slashing protection db: {err}"))?;
+            serde_json::from_slice(&raw)
+                .map_err(|err| anyhow!("Failed to parse slashing protection db: {err}"))?
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            path,
+            histories: Mutex::new(histories),
+        })
+    }
+
+    /// Check the block proposal against the recorded history and, if safe,
+    /// record the new highest slot and flush to disk before returning.
+    ///
+    /// Refuses to sign a block whose slot is `<=` the highest previously signed
+    /// slot for this validator (double proposal).
+    pub fn record_block_proposal(&self, pubkey: &PublicKey, slot: u64) -> anyhow::Result<()> {
+        let mut histories = self
+            .histories
+            .lock()
+            .map_err(|_| anyhow!("Slashing protection store poisoned"))?;
+        let history = histories.entry(pubkey.clone()).or_default();
+
+        if let Some(highest) = history.highest_signed_block_slot {
+            if slot <= highest {
+                bail!(
+                    "Refusing to sign block at slot {slot}: already signed a block at slot {highest}"
+                );
+            }
+        }
+
+        history.highest_signed_block_slot = Some(slot);
+        persist(&self.path, &histories)
+    }
+
+    /// Check the attestation against the recorded history and, if safe, record
+    /// the new extremal epochs and flush to disk before returning.
+    ///
+    /// Refuses to sign an attestation whose `target_epoch <= max_target_epoch`
+    /// (double vote) or whose `source_epoch < max_source_epoch` (surround vote).
+    pub fn record_attestation(
+        &self,
+        pubkey: &PublicKey,
+        source_epoch: u64,
+        target_epoch: u64,
+    ) -> anyhow::Result<()> {
+        let mut histories = self
+            .histories
+            .lock()
+            .map_err(|_| anyhow!("Slashing protection store poisoned"))?;
+        let history = histories.entry(pubkey.clone()).or_default();
+
+        if let Some(max_target) = history.max_signed_target_epoch {
+            if target_epoch <= max_target {
+                bail!(
+                    "Refusing to sign attestation with target epoch {target_epoch}: already signed target epoch {max_target}"
+                );
+            }
+        }
+        if let Some(max_source) = history.max_signed_source_epoch {
+            if source_epoch < max_source {
+                bail!(
+                    "Refusing to sign attestation with source epoch {source_epoch}: recorded source epoch {max_source} would be surrounded"
+                );
+            }
+        }
+
+        history.max_signed_source_epoch = Some(
+            history
+                .max_signed_source_epoch
+                .map_or(source_epoch, |current| current.max(source_epoch)),
+        );
+        history.max_signed_target_epoch = Some(target_epoch);
+        persist(&self.path, &histories)
+    }
+
+    /// Merge an EIP-3076 interchange file into the store, taking the safest
+    /// (max target/block slot, max source) bound over every imported record.
+    ///
+    /// `genesis_validators_root` is the value reported by the beacon node and is
+    /// validated against the interchange metadata before anything is imported.
+    pub fn import_interchange(
+        &self,
+        interchange: &Interchange,
+        genesis_validators_root: B256,
+    ) -> anyhow::Result<()> {
+        if interchange.metadata.interchange_format_version != INTERCHANGE_FORMAT_VERSION {
+            bail!(
+                "Unsupported interchange format version {}, expected {INTERCHANGE_FORMAT_VERSION}",
+                interchange.metadata.interchange_format_version
+            );
+        }
+        if interchange.metadata.genesis_validators_root != genesis_validators_root {
+            bail!(
+                "Interchange genesis_validators_root {:?} does not match beacon node {:?}",
+                interchange.metadata.genesis_validators_root,
+                genesis_validators_root
+            );
+        }
+
+        let mut histories = self
+            .histories
+            .lock()
+            .map_err(|_| anyhow!("Slashing protection store poisoned"))?;
+
+        for record in &interchange.data {
+            let pubkey = PublicKey::from_bytes(record.pubkey.as_ref())
+                .map_err(|err| anyhow!("Invalid pubkey in interchange: {err:?}"))?;
+            let history = histories.entry(pubkey).or_default();
+
+            for block in &record.signed_blocks {
+                history.highest_signed_block_slot = Some(
+                    history
+                        .highest_signed_block_slot
+                        .map_or(block.slot, |current| current.max(block.slot)),
+                );
+            }
+            for attestation in &record.signed_attestations {
+                history.max_signed_source_epoch = Some(
+                    history
+                        .max_signed_source_epoch
+                        .map_or(attestation.source_epoch, |current| {
+                            current.max(attestation.source_epoch)
+                        }),
+                );
+                history.max_signed_target_epoch = Some(
+                    history
+                        .max_signed_target_epoch
+                        .map_or(attestation.target_epoch, |current| {
+                            current.max(attestation.target_epoch)
+                        }),
+                );
+            }
+        }
+
+        persist(&self.path, &histories)
+    }
+
+    /// Export the tracked minimal state as an EIP-3076 interchange file.
+    pub fn export_interchange(
+        &self,
+        genesis_validators_root: B256,
+    ) -> anyhow::Result<Interchange> {
+        let histories = self
+            .histories
+            .lock()
+            .map_err(|_| anyhow!("Slashing protection store poisoned"))?;
+
+        let data = histories
+            .iter()
+            .map(|(pubkey, history)| {
+                let signed_blocks = history
+                    .highest_signed_block_slot
+                    .map(|slot| {
+                        vec![InterchangeBlock {
+                            slot,
+                            signing_root: None,
+                        }]
+                    })
+                    .unwrap_or_default();
+                let signed_attestations = match history.max_signed_target_epoch {
+                    Some(target_epoch) => vec![InterchangeAttestation {
+                        source_epoch: history.max_signed_source_epoch.unwrap_or_default(),
+                        target_epoch,
+                        signing_root: None,
+                    }],
+                    None => Vec::new(),
+                };
+
+                InterchangeRecord {
+                    pubkey: pubkey.to_bytes().into(),
+                    signed_blocks,
+                    signed_attestations,
+                }
+            })
+            .collect();
+
+        Ok(Interchange {
+            metadata: InterchangeMetadata {
+                interchange_format_version: INTERCHANGE_FORMAT_VERSION.to_string(),
+                genesis_validators_root,
+            },
+            data,
+        })
+    }
+}
+
+/// Atomically replace the on-disk store with the current in-memory state.
+fn persist(path: &Path, histories: &HashMap<PublicKey, ValidatorHistory>) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| anyhow!("Failed to create slashing protection dir: {err}"))?;
+    }
+
+    let serialized = serde_json::to_vec_pretty(histories)
+        .map_err(|err| anyhow!("Failed to serialize slashing protection db: {err}"))?;
+
+    let tmp_path = path.with_extension("tmp");
+    let mut file = fs::File::create(&tmp_path)
+        .map_err(|err| anyhow!("Failed to open slashing protection tmp file: {err}"))?;
+    file.write_all(&serialized)
+        .map_err(|err| anyhow!("Failed to write slashing protection db: {err}"))?;
+    file.sync_all()
+        .map_err(|err| anyhow!("Failed to flush slashing protection db: {err}"))?;
+    fs::rename(&tmp_path, path)
+        .map_err(|err| anyhow!("Failed to persist slashing protection db: {err}"))?;
+
+    Ok(())
+}
+
+/// Top-level EIP-3076 interchange document.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Interchange {
+    pub metadata: InterchangeMetadata,
+    pub data: Vec<InterchangeRecord>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InterchangeMetadata {
+    pub interchange_format_version: String,
+    pub genesis_validators_root: B256,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InterchangeRecord {
+    pub pubkey: alloy_primitives::Bytes,
+    #[serde(default)]
+    pub signed_blocks: Vec<InterchangeBlock>,
+    #[serde(default)]
+    pub signed_attestations: Vec<InterchangeAttestation>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InterchangeBlock {
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub slot: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_root: Option<B256>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InterchangeAttestation {
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub source_epoch: u64,
+    #[serde(with = "serde_utils::quoted_u64")]
+    pub target_epoch: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_root: Option<B256>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use alloy_primitives::B256;
+    use ream_bls::{PrivateKey, PublicKey};
+
+    use super::*;
+
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    fn temp_db_path() -> PathBuf {
+        let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!(
+            "ream-slashing-protection-{}-{unique}.json",
+            std::process::id()
+        ))
+    }
+
+    fn test_keypair(seed: u8) -> (PublicKey, alloy_primitives::Bytes) {
+        let mut secret = [0u8; 32];
+        secret[31] = seed;
+        let private_key = PrivateKey::from_bytes(&secret).expect("valid secret key");
+        let public_key = private_key.public_key();
+        let bytes = public_key.to_bytes().into();
+        (public_key, bytes)
+    }
+
+    #[test]
+    fn rejects_double_block_proposal() {
+        let store = SlashingProtection::new(temp_db_path()).unwrap();
+        let (pubkey, _) = test_keypair(1);
+
+        store.record_block_proposal(&pubkey, 5).unwrap();
+        // Re-signing the same or an earlier slot is a double proposal.
+        assert!(store.record_block_proposal(&pubkey, 5).is_err());
+        assert!(store.record_block_proposal(&pubkey, 4).is_err());
+        // A strictly higher slot is allowed.
+        store.record_block_proposal(&pubkey, 6).unwrap();
+    }
+
+    #[test]
+    fn rejects_double_vote_attestation() {
+        let store = SlashingProtection::new(temp_db_path()).unwrap();
+        let (pubkey, _) = test_keypair(2);
+
+        store.record_attestation(&pubkey, 1, 5).unwrap();
+        // target_epoch <= max_signed_target_epoch is a double vote.
+        assert!(store.record_attestation(&pubkey, 2, 5).is_err());
+        assert!(store.record_attestation(&pubkey, 2, 4).is_err());
+    }
+
+    #[test]
+    fn rejects_surround_vote_attestation() {
+        let store = SlashingProtection::new(temp_db_path()).unwrap();
+        let (pubkey, _) = test_keypair(3);
+
+        store.record_attestation(&pubkey, 3, 5).unwrap();
+        // source_epoch < max_signed_source_epoch would surround a prior vote.
+        assert!(store.record_attestation(&pubkey, 2, 6).is_err());
+    }
+
+    #[test]
+    fn import_export_round_trip() {
+        let genesis_validators_root = B256::repeat_byte(0xab);
+        let (pubkey, pubkey_bytes) = test_keypair(4);
+
+        let source = SlashingProtection::new(temp_db_path()).unwrap();
+        source.record_block_proposal(&pubkey, 7).unwrap();
+        source.record_attestation(&pubkey, 2, 4).unwrap();
+        let exported = source.export_interchange(genesis_validators_root).unwrap();
+
+        let imported = SlashingProtection::new(temp_db_path()).unwrap();
+        imported
+            .import_interchange(&exported, genesis_validators_root)
+            .unwrap();
+        let round_tripped = imported.export_interchange(genesis_validators_root).unwrap();
+
+        assert_eq!(round_tripped.data.len(), 1);
+        let record = &round_tripped.data[0];
+        assert_eq!(record.pubkey, pubkey_bytes);
+        assert_eq!(record.signed_blocks[0].slot, 7);
+        assert_eq!(record.signed_attestations[0].source_epoch, 2);
+        assert_eq!(record.signed_attestations[0].target_epoch, 4);
+    }
+
+    #[test]
+    fn rejects_unsupported_interchange_version() {
+        let genesis_validators_root = B256::repeat_byte(0xcd);
+        let interchange = Interchange {
+            metadata: InterchangeMetadata {
+                interchange_format_version: "4".to_string(),
+                genesis_validators_root,
+            },
+            data: Vec::new(),
+        };
+
+        let store = SlashingProtection::new(temp_db_path()).unwrap();
+        assert!(
+            store
+                .import_interchange(&interchange, genesis_validators_root)
+                .is_err()
+        );
+    }
+}
+
+/// Serde helpers for the quoted-decimal integers mandated by EIP-3076.
+mod serde_utils {
+    pub mod quoted_u64 {
+        use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+        pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&value.to_string())
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+            let raw = String::deserialize(deserializer)?;
+            raw.parse().map_err(D::Error::custom)
+        }
+    }
+}
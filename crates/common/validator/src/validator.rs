@@ -1,11 +1,12 @@
 use std::{
     collections::{HashMap, hash_map::Entry},
-    sync::Arc,
+    path::PathBuf,
+    sync::{Arc, RwLock},
     time::{Duration, SystemTime, UNIX_EPOCH},
     vec,
 };
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use anyhow::{anyhow, bail};
 use ream_beacon_api_types::{
     block::{BroadcastValidation, ProduceBlockData},
@@ -13,7 +14,7 @@ use ream_beacon_api_types::{
     id::{ID, ValidatorID},
     request::SyncCommitteeRequestItem,
 };
-use ream_bls::{PublicKey, traits::Signable};
+use ream_bls::{PublicKey, PublicKeyBytes, traits::Signable};
 use ream_consensus::{
     attestation_data::AttestationData,
     constants::DOMAIN_SYNC_COMMITTEE,
@@ -31,12 +32,19 @@ use tree_hash::TreeHash;
 
 use crate::{
     aggregate_and_proof::{AggregateAndProof, SignedAggregateAndProof, sign_aggregate_and_proof},
-    attestation::{get_selection_proof, sign_attestation_data},
+    attestation::{get_selection_proof, is_aggregator, sign_attestation_data},
     beacon_api_client::BeaconApiClient,
     block::{sign_beacon_block, sign_blinded_beacon_block},
     randao::sign_randao_reveal,
+    slashing_protection::{Interchange, SlashingProtection},
+    validator_registration::{
+        DEFAULT_GAS_LIMIT, ValidatorRegistrationV1, sign_validator_registration,
+    },
 };
 
+/// How often to probe the beacon node's readiness while waiting for genesis.
+const GENESIS_READINESS_PROBE_INTERVAL: Duration = Duration::from_secs(12);
+
 pub fn check_if_validator_active(
     state: &BeaconState,
     validator_index: u64,
@@ -56,22 +64,29 @@ pub struct ValidatorService {
     pub beacon_api_client: Arc<BeaconApiClient>,
     pub validators: Vec<Arc<Keystore>>,
     pub suggested_fee_recipient: Arc<Address>,
+    pub validator_fee_recipients: HashMap<PublicKey, Address>,
+    pub validator_gas_limits: HashMap<PublicKey, u64>,
     pub executor: ReamExecutor,
-    pub active_validator_count: usize,
-    pub public_key_to_index: HashMap<PublicKey, u64>,
-    pub validator_index_to_keystore: HashMap<u64, Arc<Keystore>>,
-    pub proposer_duties: Vec<ProposerDuty>,
-    pub attester_duties: Vec<AttesterDuty>,
-    pub sync_committee_duties: Vec<SyncCommitteeDuty>,
+    pub slashing_protection: Arc<SlashingProtection>,
+    pub public_key_to_index: RwLock<HashMap<PublicKeyBytes, u64>>,
+    pub validator_index_to_keystore: Arc<RwLock<HashMap<u64, Arc<Keystore>>>>,
+    pub proposer_duties: Arc<RwLock<Vec<ProposerDuty>>>,
+    pub proposer_duties_dependent_root: RwLock<Option<B256>>,
+    pub attester_duties: Arc<RwLock<Vec<AttesterDuty>>>,
+    pub attester_duties_dependent_root: RwLock<Option<B256>>,
+    pub sync_committee_duties: Arc<RwLock<Vec<SyncCommitteeDuty>>>,
 }
 
 impl ValidatorService {
     pub fn new(
         keystores: Vec<Keystore>,
         suggested_fee_recipient: Address,
+        validator_fee_recipients: HashMap<PublicKey, Address>,
+        validator_gas_limits: HashMap<PublicKey, u64>,
         beacon_api_endpoint: Url,
         request_timeout: Duration,
         executor: ReamExecutor,
+        slashing_protection_path: PathBuf,
     ) -> anyhow::Result<Self> {
         let validators = keystores.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -82,18 +97,23 @@ impl ValidatorService {
             )?),
             validators,
             suggested_fee_recipient: Arc::new(suggested_fee_recipient),
+            validator_fee_recipients,
+            validator_gas_limits,
             executor,
-            active_validator_count: 0,
-            public_key_to_index: HashMap::new(),
-            validator_index_to_keystore: HashMap::new(),
-            proposer_duties: Vec::new(),
-            attester_duties: Vec::new(),
-            sync_committee_duties: Vec::new(),
+            slashing_protection: Arc::new(SlashingProtection::new(slashing_protection_path)?),
+            public_key_to_index: RwLock::new(HashMap::new()),
+            validator_index_to_keystore: Arc::new(RwLock::new(HashMap::new())),
+            proposer_duties: Arc::new(RwLock::new(Vec::new())),
+            proposer_duties_dependent_root: RwLock::new(None),
+            attester_duties: Arc::new(RwLock::new(Vec::new())),
+            attester_duties_dependent_root: RwLock::new(None),
+            sync_committee_duties: Arc::new(RwLock::new(Vec::new())),
         })
     }
 
-    pub async fn start(mut self) {
-        let genesis_info = self
+    pub async fn start(self) {
+        let service = Arc::new(self);
+        let genesis_info = service
             .beacon_api_client
             .get_genesis()
             .await
@@ -101,9 +121,16 @@ impl ValidatorService {
 
         let seconds_per_slot = network_spec().seconds_per_slot;
         let genesis_instant = UNIX_EPOCH + Duration::from_secs(genesis_info.data.genesis_time);
-        let elapsed = SystemTime::now()
-            .duration_since(genesis_instant)
-            .expect("System Time is before the genesis time");
+        let elapsed = match SystemTime::now().duration_since(genesis_instant) {
+            Ok(elapsed) => elapsed,
+            Err(before_genesis) => {
+                // Booting before genesis is expected for stakers spinning up
+                // early: wait it out instead of panicking, probing the beacon
+                // node for readiness in the meantime.
+                service.wait_for_genesis(before_genesis.duration()).await;
+                Duration::ZERO
+            }
+        };
 
         let mut slot = elapsed.as_secs() / seconds_per_slot;
         let mut epoch = compute_epoch_at_slot(slot);
@@ -115,6 +142,11 @@ impl ValidatorService {
         };
         interval.set_missed_tick_behavior(MissedTickBehavior::Burst);
 
+        // Discover validators and fetch duties for the starting epoch up front
+        // so the VC acts on duties from its very first slot instead of idling
+        // until the next epoch boundary.
+        service.on_epoch(epoch).await;
+
         loop {
             tokio::select! {
                 _ = interval.tick() => {
@@ -123,20 +155,246 @@ impl ValidatorService {
 
                     if current_epoch != epoch {
                         epoch = current_epoch;
-                        self.on_epoch(epoch).await;
+                        service.on_epoch(epoch).await;
                     }
-                    self.on_slot(slot);
+                    service.clone().on_slot(slot, seconds_per_slot);
                 }
             }
         }
     }
 
-    pub fn on_slot(&self, slot: u64) {
+    /// Sleep until genesis, probing the beacon node for readiness on a fixed
+    /// cadence so that operators can fix a mis-configured or unsynced node
+    /// before the chain starts.
+    async fn wait_for_genesis(&self, until_genesis: Duration) {
+        warn!(
+            "System time is before genesis; waiting {until_genesis:?} for genesis before starting validator duties"
+        );
+        let genesis_deadline = Instant::now() + until_genesis;
+        let mut probe = interval_at(Instant::now(), GENESIS_READINESS_PROBE_INTERVAL);
+        probe.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep_until(genesis_deadline) => {
+                    info!("Genesis reached; starting validator duties");
+                    break;
+                }
+                _ = probe.tick() => {
+                    self.check_node_readiness().await;
+                }
+            }
+        }
+    }
+
+    /// Probe the beacon node's syncing status, logging a clear error if it is
+    /// not ready to support staking.
+    async fn check_node_readiness(&self) {
+        match self.beacon_api_client.get_syncing_status().await {
+            Ok(syncing) if syncing.data.is_syncing => {
+                error!(
+                    "Beacon node is still syncing ahead of genesis (sync distance {}); it must be synced before it can stake",
+                    syncing.data.sync_distance
+                );
+            }
+            Ok(_) => {
+                info!("Beacon node reports ready to stake");
+            }
+            Err(err) => {
+                error!(
+                    "Beacon node is not reachable or not configured to stake, please check your setup before genesis: {err:?}"
+                );
+            }
+        }
+    }
+
+    /// Kick off the duties scheduled for `slot`.
+    ///
+    /// Block proposal and attestation/aggregation run as independent tasks on
+    /// the [`ReamExecutor`] so a slow attestation-data fetch or aggregation can
+    /// never delay a timely block broadcast, and vice versa. The shared duty
+    /// caches are read through their locks inside each task.
+    pub fn on_slot(self: Arc<Self>, slot: u64, seconds_per_slot: u64) {
         info!("Current Slot: {slot}");
+
+        let slot_start = Instant::now();
+        let one_third = Duration::from_secs(seconds_per_slot) / 3;
+        let two_thirds = 2 * one_third;
+
+        let block_service = self.clone();
+        self.executor.spawn(async move {
+            block_service.run_block_duties(slot).await;
+        });
+
+        let attestation_service = self.clone();
+        self.executor.spawn(async move {
+            attestation_service
+                .run_attestation_duties(slot, slot_start + one_third, slot_start + two_thirds)
+                .await;
+        });
+
+        let sync_service = self.clone();
+        self.executor.spawn(async move {
+            sync_service
+                .run_sync_committee_duties(slot, slot_start + one_third)
+                .await;
+        });
+
+        let reorg_service = self;
+        reorg_service.executor.spawn({
+            let reorg_service = reorg_service.clone();
+            async move {
+                reorg_service
+                    .refresh_duties_on_reorg(compute_epoch_at_slot(slot))
+                    .await;
+            }
+        });
+    }
+
+    /// Detect a reorg that changed the shuffling for the in-progress epoch and,
+    /// only then, re-fetch the affected duties.
+    ///
+    /// The proposer and attester duties endpoints are re-queried and the
+    /// `dependent_root` they return is compared against the root recorded when
+    /// the duties were last fetched. Only when that root has actually changed
+    /// (a reorg altered the shuffling) are the cached duties filtered and
+    /// replaced; the validator indices are still collected and both endpoints
+    /// still queried on every call.
+    async fn refresh_duties_on_reorg(&self, epoch: u64) {
+        let validator_indices: Vec<u64> = self
+            .public_key_to_index
+            .read()
+            .expect("Public key index lock poisoned")
+            .values()
+            .cloned()
+            .collect();
+
+        if validator_indices.is_empty() {
+            return;
+        }
+
+        self.fetch_proposer_duties(epoch, &validator_indices).await;
+        self.fetch_attester_duties(epoch, &validator_indices).await;
+    }
+
+    /// Propose a block for any proposer duty matching this slot.
+    async fn run_block_duties(&self, slot: u64) {
+        let duties = self
+            .proposer_duties
+            .read()
+            .expect("Proposer duties lock poisoned")
+            .iter()
+            .filter(|duty| duty.slot == slot)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for duty in duties {
+            if let Err(err) = self.propose_block(slot, duty.validator_index).await {
+                error!(
+                    "Failed to propose block for validator {}: {err:?}",
+                    duty.validator_index
+                );
+            }
+        }
+    }
+
+    /// Attest at `attest_at` and publish aggregates at `aggregate_at` for every
+    /// attester duty matching this slot.
+    async fn run_attestation_duties(&self, slot: u64, attest_at: Instant, aggregate_at: Instant) {
+        let duties = self
+            .attester_duties
+            .read()
+            .expect("Attester duties lock poisoned")
+            .iter()
+            .filter(|duty| duty.slot == slot)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        tokio::time::sleep_until(attest_at).await;
+        for duty in &duties {
+            if let Err(err) = self
+                .make_attestation(slot, duty.validator_index, duty.committee_index)
+                .await
+            {
+                error!(
+                    "Failed to attest for validator {}: {err:?}",
+                    duty.validator_index
+                );
+            }
+        }
+
+        tokio::time::sleep_until(aggregate_at).await;
+        for duty in &duties {
+            if let Err(err) = self.try_aggregate(slot, duty).await {
+                error!(
+                    "Failed to aggregate for validator {}: {err:?}",
+                    duty.validator_index
+                );
+            }
+        }
+    }
+
+    /// Sign sync-committee messages for every current sync-committee member.
+    ///
+    /// Like attestations, these are produced at 1/3 of the slot, over the
+    /// block root of `slot`, so we wait until `sign_at` before fetching that
+    /// root and signing.
+    async fn run_sync_committee_duties(&self, slot: u64, sign_at: Instant) {
+        let sync_committee_indices = self
+            .sync_committee_duties
+            .read()
+            .expect("Sync committee duties lock poisoned")
+            .iter()
+            .map(|duty| duty.validator_index)
+            .collect::<Vec<_>>();
+
+        if sync_committee_indices.is_empty() {
+            return;
+        }
+
+        tokio::time::sleep_until(sign_at).await;
+        if let Err(err) = self.submit_sync_committee(slot, &sync_committee_indices).await {
+            error!("Failed to submit sync committee signatures: {err:?}");
+        }
     }
 
-    pub async fn fetch_validator_indicies(&mut self) {
-        if self.active_validator_count < self.validators.len() {
+    /// Publish an aggregate and proof for `duty` if its selection proof marks
+    /// the validator as an aggregator for this slot.
+    async fn try_aggregate(&self, slot: u64, duty: &AttesterDuty) -> anyhow::Result<()> {
+        let keystore = self
+            .validator_index_to_keystore
+            .read()
+            .expect("Validator keystore lock poisoned")
+            .get(&duty.validator_index)
+            .cloned()
+            .ok_or_else(|| anyhow!("Keystore not found for validator: {}", duty.validator_index))?;
+
+        let selection_proof = get_selection_proof(slot, &keystore.private_key)?;
+        if !is_aggregator(duty.committee_length, &selection_proof) {
+            return Ok(());
+        }
+
+        let attestation_data = self
+            .beacon_api_client
+            .get_attestation_data(slot, duty.committee_index)
+            .await?
+            .data;
+        self.submit_aggregate_and_proof(
+            attestation_data,
+            slot,
+            duty.committee_index,
+            duty.validator_index,
+        )
+        .await
+    }
+
+    pub async fn fetch_validator_indicies(&self) {
+        let active_validator_count = self
+            .public_key_to_index
+            .read()
+            .expect("Public key index lock poisoned")
+            .len();
+        if active_validator_count < self.validators.len() {
             let validator_states = self
                 .beacon_api_client
                 .get_state_validator_list(
@@ -154,10 +412,18 @@ impl ValidatorService {
                 .await;
 
             if let Ok(validator_infos) = validator_states {
+                let mut public_key_to_index = self
+                    .public_key_to_index
+                    .write()
+                    .expect("Public key index lock poisoned");
+                let mut validator_index_to_keystore = self
+                    .validator_index_to_keystore
+                    .write()
+                    .expect("Validator keystore lock poisoned");
+
                 validator_infos.data.into_iter().for_each(|validator_data| {
-                    if let Entry::Vacant(entry) = self
-                        .public_key_to_index
-                        .entry(validator_data.validator.public_key.clone())
+                    if let Entry::Vacant(entry) = public_key_to_index
+                        .entry(PublicKeyBytes::from(&validator_data.validator.public_key))
                     {
                         entry.insert(validator_data.index);
 
@@ -169,19 +435,22 @@ impl ValidatorService {
                             })
                             .cloned()
                         {
-                            self.validator_index_to_keystore
-                                .insert(validator_data.index, keystore);
+                            validator_index_to_keystore.insert(validator_data.index, keystore);
                         }
-
-                        self.active_validator_count += 1;
                     }
                 });
             }
         }
     }
 
-    pub async fn fetch_duties(&mut self, epoch: u64) {
-        let validator_indices: Vec<u64> = self.public_key_to_index.values().cloned().collect();
+    pub async fn fetch_duties(&self, epoch: u64) {
+        let validator_indices: Vec<u64> = self
+            .public_key_to_index
+            .read()
+            .expect("Public key index lock poisoned")
+            .values()
+            .cloned()
+            .collect();
 
         if validator_indices.is_empty() {
             warn!("No active validators found, skipping duty fetch");
@@ -189,16 +458,34 @@ impl ValidatorService {
         }
 
         self.fetch_proposer_duties(epoch, &validator_indices).await;
-        self.fetch_attester_duties(epoch + 1, &validator_indices)
-            .await;
+        self.fetch_attester_duties(epoch, &validator_indices).await;
         self.fetch_sync_committee_duties(epoch, &validator_indices)
             .await;
     }
 
-    pub async fn fetch_proposer_duties(&mut self, epoch: u64, validator_indices: &[u64]) {
+    pub async fn fetch_proposer_duties(&self, epoch: u64, validator_indices: &[u64]) {
         match self.beacon_api_client.get_proposer_duties(epoch).await {
             Ok(duties_response) => {
-                self.proposer_duties = duties_response
+                {
+                    let mut dependent_root = self
+                        .proposer_duties_dependent_root
+                        .write()
+                        .expect("Proposer dependent root lock poisoned");
+                    match *dependent_root {
+                        // Same shuffling as last time: keep the cached duties.
+                        Some(root) if root == duties_response.dependent_root => return,
+                        Some(_) => warn!(
+                            "Reorg detected for proposer duties at epoch {epoch}: dependent root changed, refreshing"
+                        ),
+                        None => {}
+                    }
+                    *dependent_root = Some(duties_response.dependent_root);
+                }
+
+                *self
+                    .proposer_duties
+                    .write()
+                    .expect("Proposer duties lock poisoned") = duties_response
                     .data
                     .into_iter()
                     .filter(|duty| validator_indices.contains(&duty.validator_index))
@@ -210,14 +497,33 @@ impl ValidatorService {
         }
     }
 
-    pub async fn fetch_attester_duties(&mut self, epoch: u64, validator_indices: &[u64]) {
+    pub async fn fetch_attester_duties(&self, epoch: u64, validator_indices: &[u64]) {
         match self
             .beacon_api_client
             .get_attester_duties(epoch, validator_indices)
             .await
         {
             Ok(duties_response) => {
-                self.attester_duties = duties_response.data;
+                {
+                    let mut dependent_root = self
+                        .attester_duties_dependent_root
+                        .write()
+                        .expect("Attester dependent root lock poisoned");
+                    match *dependent_root {
+                        // Same shuffling as last time: keep the cached duties.
+                        Some(root) if root == duties_response.dependent_root => return,
+                        Some(_) => warn!(
+                            "Reorg detected for attester duties at epoch {epoch}: dependent root changed, refreshing"
+                        ),
+                        None => {}
+                    }
+                    *dependent_root = Some(duties_response.dependent_root);
+                }
+
+                *self
+                    .attester_duties
+                    .write()
+                    .expect("Attester duties lock poisoned") = duties_response.data;
             }
             Err(err) => {
                 error!("Failed to fetch attester duties for epoch {epoch}: {err:?}");
@@ -225,14 +531,17 @@ impl ValidatorService {
         }
     }
 
-    pub async fn fetch_sync_committee_duties(&mut self, epoch: u64, validator_indices: &[u64]) {
+    pub async fn fetch_sync_committee_duties(&self, epoch: u64, validator_indices: &[u64]) {
         match self
             .beacon_api_client
             .get_sync_committee_duties(epoch, validator_indices)
             .await
         {
             Ok(duties_response) => {
-                self.sync_committee_duties = duties_response.data;
+                *self
+                    .sync_committee_duties
+                    .write()
+                    .expect("Sync committee duties lock poisoned") = duties_response.data;
             }
             Err(err) => {
                 error!("Failed to fetch sync committee duties for epoch {epoch}: {err:?}");
@@ -243,6 +552,8 @@ impl ValidatorService {
     pub async fn propose_block(&self, slot: u64, validator_index: u64) -> anyhow::Result<()> {
         let keystore = self
             .validator_index_to_keystore
+            .read()
+            .expect("Validator keystore lock poisoned")
             .get(&validator_index)
             .cloned()
             .ok_or_else(|| anyhow!("Keystore not found for validator: {validator_index}"))?;
@@ -252,6 +563,9 @@ impl ValidatorService {
             .produce_block(slot, randao_reveal, None, None, None)
             .await?;
 
+        self.slashing_protection
+            .record_block_proposal(&keystore.public_key, slot)?;
+
         match block_response.data {
             ProduceBlockData::Full(full_block) => {
                 let signed_beacon_block =
@@ -292,25 +606,31 @@ impl ValidatorService {
             .root;
         let signing_root = compute_signing_root(beacon_block_root, domain);
 
-        let payload = validator_indices
-            .iter()
-            .filter_map(|&validator_index| {
-                if let Some(keystore) = self.validator_index_to_keystore.get(&validator_index) {
-                    return match keystore.private_key.sign(signing_root.as_ref()) {
-                        Ok(signature) => Some(Ok(SyncCommitteeRequestItem {
-                            slot,
-                            beacon_block_root,
-                            validator_index,
-                            signature,
-                        })),
-                        Err(signing_error) => Some(Err(anyhow!(
-                            "Signing failed for validator {validator_index:?}: {signing_error:?}"
-                        ))),
-                    };
-                }
-                None
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+        let payload = {
+            let keystores = self
+                .validator_index_to_keystore
+                .read()
+                .expect("Validator keystore lock poisoned");
+            validator_indices
+                .iter()
+                .filter_map(|&validator_index| {
+                    if let Some(keystore) = keystores.get(&validator_index) {
+                        return match keystore.private_key.sign(signing_root.as_ref()) {
+                            Ok(signature) => Some(Ok(SyncCommitteeRequestItem {
+                                slot,
+                                beacon_block_root,
+                                validator_index,
+                                signature,
+                            })),
+                            Err(signing_error) => Some(Err(anyhow!(
+                                "Signing failed for validator {validator_index:?}: {signing_error:?}"
+                            ))),
+                        };
+                    }
+                    None
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
 
         Ok(self
             .beacon_api_client
@@ -324,7 +644,13 @@ impl ValidatorService {
         validator_index: u64,
         committee_index: u64,
     ) -> anyhow::Result<()> {
-        let Some(keystore) = self.validator_index_to_keystore.get(&validator_index) else {
+        let Some(keystore) = self
+            .validator_index_to_keystore
+            .read()
+            .expect("Validator keystore lock poisoned")
+            .get(&validator_index)
+            .cloned()
+        else {
             bail!("Keystore not found for validator: {validator_index}");
         };
 
@@ -333,6 +659,13 @@ impl ValidatorService {
             .get_attestation_data(slot, committee_index)
             .await?
             .data;
+
+        self.slashing_protection.record_attestation(
+            &keystore.public_key,
+            attestation_data.source.epoch,
+            attestation_data.target.epoch,
+        )?;
+
         Ok(self
             .beacon_api_client
             .submit_attestation(vec![SingleAttestation {
@@ -353,6 +686,8 @@ impl ValidatorService {
     ) -> anyhow::Result<()> {
         let keystore = self
             .validator_index_to_keystore
+            .read()
+            .expect("Validator keystore lock poisoned")
             .get(&aggregator_index)
             .cloned()
             .ok_or_else(|| anyhow!("Keystore not found for validator: {aggregator_index}"))?;
@@ -380,8 +715,93 @@ impl ValidatorService {
             .await?)
     }
 
-    pub async fn on_epoch(&mut self, epoch: u64) {
+    /// Import an EIP-3076 interchange file, validating its
+    /// `genesis_validators_root` against the beacon node's genesis.
+    pub async fn import_slashing_protection(
+        &self,
+        interchange: &Interchange,
+    ) -> anyhow::Result<()> {
+        let genesis_validators_root = self
+            .beacon_api_client
+            .get_genesis()
+            .await?
+            .data
+            .genesis_validators_root;
+        self.slashing_protection
+            .import_interchange(interchange, genesis_validators_root)
+    }
+
+    /// Export the tracked slashing-protection state as an EIP-3076 interchange
+    /// file stamped with the beacon node's `genesis_validators_root`.
+    pub async fn export_slashing_protection(&self) -> anyhow::Result<Interchange> {
+        let genesis_validators_root = self
+            .beacon_api_client
+            .get_genesis()
+            .await?
+            .data
+            .genesis_validators_root;
+        self.slashing_protection
+            .export_interchange(genesis_validators_root)
+    }
+
+    /// Build and submit `SignedValidatorRegistrationV1` messages for every
+    /// active validator so that relays will offer blinded payloads.
+    ///
+    /// Registrations expire on the relay side, so this is re-sent on a fixed
+    /// cadence from [`on_epoch`](Self::on_epoch). `fee_recipient` and
+    /// `gas_limit` are taken from the per-validator overrides, falling back to
+    /// [`suggested_fee_recipient`](Self::suggested_fee_recipient) and
+    /// [`DEFAULT_GAS_LIMIT`] respectively.
+    pub async fn register_validators(&self) -> anyhow::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|err| anyhow!("System time is before the unix epoch: {err}"))?
+            .as_secs();
+
+        let keystores = self
+            .validator_index_to_keystore
+            .read()
+            .expect("Validator keystore lock poisoned")
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        let registrations = keystores
+            .iter()
+            .map(|keystore| {
+                let message = ValidatorRegistrationV1 {
+                    fee_recipient: self
+                        .validator_fee_recipients
+                        .get(&keystore.public_key)
+                        .copied()
+                        .unwrap_or(*self.suggested_fee_recipient),
+                    gas_limit: self
+                        .validator_gas_limits
+                        .get(&keystore.public_key)
+                        .copied()
+                        .unwrap_or(DEFAULT_GAS_LIMIT),
+                    timestamp,
+                    pubkey: keystore.public_key.clone(),
+                };
+                sign_validator_registration(message, &keystore.private_key)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if registrations.is_empty() {
+            return Ok(());
+        }
+
+        Ok(self
+            .beacon_api_client
+            .register_validators(registrations)
+            .await?)
+    }
+
+    pub async fn on_epoch(&self, epoch: u64) {
         self.fetch_validator_indicies().await;
+        self.fetch_duties(epoch).await;
+        if let Err(err) = self.register_validators().await {
+            error!("Failed to register validators with builder endpoint: {err:?}");
+        }
         info!("Current Epoch: {epoch}");
     }
 }
@@ -0,0 +1,48 @@
+use alloy_primitives::Address;
+use anyhow::anyhow;
+use ream_bls::{BLSSignature, PrivateKey, PublicKey, traits::Signable};
+use ream_consensus::{
+    constants::DOMAIN_APPLICATION_BUILDER,
+    misc::{compute_domain, compute_signing_root},
+};
+use serde::{Deserialize, Serialize};
+use ssz_derive::{Decode, Encode};
+use tree_hash_derive::TreeHash;
+
+/// Default execution gas limit advertised to builders when a validator has no
+/// explicit override configured.
+pub const DEFAULT_GAS_LIMIT: u64 = 30_000_000;
+
+/// `ValidatorRegistrationV1` as consumed by the builder `register_validator`
+/// endpoint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Encode, Decode, TreeHash)]
+pub struct ValidatorRegistrationV1 {
+    pub fee_recipient: Address,
+    pub gas_limit: u64,
+    pub timestamp: u64,
+    pub pubkey: PublicKey,
+}
+
+/// `SignedValidatorRegistrationV1`, signed with the builder domain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Encode, Decode, TreeHash)]
+pub struct SignedValidatorRegistrationV1 {
+    pub message: ValidatorRegistrationV1,
+    pub signature: BLSSignature,
+}
+
+/// Sign a validator registration with the application builder domain.
+///
+/// The builder domain is application-level and is computed against the genesis
+/// fork version, so no fork version is supplied to [`compute_domain`].
+pub fn sign_validator_registration(
+    message: ValidatorRegistrationV1,
+    private_key: &PrivateKey,
+) -> anyhow::Result<SignedValidatorRegistrationV1> {
+    let domain = compute_domain(DOMAIN_APPLICATION_BUILDER, None, None);
+    let signing_root = compute_signing_root(message.tree_hash_root(), domain);
+    let signature = private_key
+        .sign(signing_root.as_ref())
+        .map_err(|err| anyhow!("Failed to sign validator registration: {err:?}"))?;
+
+    Ok(SignedValidatorRegistrationV1 { message, signature })
+}